@@ -10,8 +10,8 @@ fn main() {
     let list: LinkedList<u32> = linked_list![1,2,3];
     println!("{:?}", list.pop());
 
-    let list_a = &LinkedList::from_iter((0..100));
-    let list_b = &LinkedList::from_iter((200..300));
+    let list_a = &LinkedList::from_iter(0..100);
+    let list_b = &LinkedList::from_iter(200..300);
     println!("{:?}", list_a.into_iter().collect::<Vec<_>>());
     println!("{:?}", list_a.into_iter().next());
     println!("{:?}", list_a.into_iter().last());
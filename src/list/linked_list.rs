@@ -1,5 +1,10 @@
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
 use std::mem;
 use std::iter::FromIterator;
+use tree::BinaryTree;
 
 #[macro_export]
 /// Create a linked list with elements pushed into it. Much like the `vec!`
@@ -23,9 +28,10 @@ pub type MoveResult<T, U> = Result<T, LinkedList<U>>;
 /// and as reference of the details of a recursive type. You probably shouldn't
 /// use this data structure in real world applications.
 ///
-#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub enum LinkedList<T> {
     Cons(T, Box<LinkedList<T>>),
+    #[default]
     Nil,
 }
 
@@ -34,12 +40,17 @@ pub struct Iter<'a, T: 'a> {
     current: &'a LinkedList<T>,
 }
 
+/// Iterator for lists by mutable reference.
+pub struct IterMut<'a, T: 'a> {
+    current: Option<&'a mut LinkedList<T>>,
+}
+
 /// Iterator for lists by value.
 pub struct IntoIter<T> {
     current: LinkedList<T>,
 }
 
-impl<'a, T> LinkedList<T> {
+impl<T> LinkedList<T> {
     /// Return a new empty linked list. This is semantically equivlent to
     /// `List::Nil`.
     ///
@@ -87,6 +98,31 @@ impl<'a, T> LinkedList<T> {
         self.into_iter().count()
     }
 
+    /// Compare the length of this list with the length of `other`. Both
+    /// lists are walked together, stopping as soon as either one ends, so
+    /// this takes time proportional to the shorter list. Calling `len()` on
+    /// both would walk each list fully.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::cmp::Ordering;
+    /// use structures::list::LinkedList;
+    ///
+    /// let list_a = LinkedList::new().push(1);
+    /// let list_b = LinkedList::new().push(1).push(2);
+    ///
+    /// assert_eq!(list_a.cmp_len(&list_b), Ordering::Less);
+    /// ```
+    pub fn cmp_len(&self, other: &LinkedList<T>) -> Ordering {
+        match (self, other) {
+            (LinkedList::Cons(_, a), LinkedList::Cons(_, b)) => a.cmp_len(b),
+            (LinkedList::Cons(_, _), LinkedList::Nil) => Ordering::Greater,
+            (LinkedList::Nil, LinkedList::Cons(_, _)) => Ordering::Less,
+            (LinkedList::Nil, LinkedList::Nil) => Ordering::Equal,
+        }
+    }
+
     /// Add an element to the front of the list. Insert is constant time
     /// because we return the new list containing the item, and the old now
     /// moved list.
@@ -134,6 +170,102 @@ impl<'a, T> LinkedList<T> {
         }
     }
 
+    /// Return a reference to the item at the given index, or `None` if the
+    /// index is out of bounds. Indexing starts at 0, see `insert` for an
+    /// example of the indexing. Unlike `remove`, the list is only borrowed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    ///
+    /// assert_eq!(list.get(1), Some(&2));
+    /// assert_eq!(list.get(3), None);
+    /// ```
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.into_iter().nth(index)
+    }
+
+    /// Return a mutable reference to the item at the given index, or `None`
+    /// if the index is out of bounds. This allows changing an item in place
+    /// without rebuilding the list around it.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let mut list = LinkedList::new().push(3).push(2).push(1);
+    /// *list.get_mut(1).unwrap() = 42;
+    ///
+    /// assert_eq!(list, LinkedList::new().push(3).push(42).push(1));
+    /// ```
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        match *self {
+            LinkedList::Cons(ref mut item, ref mut rest) => {
+                if index == 0 {
+                    Some(item)
+                } else {
+                    rest.get_mut(index - 1)
+                }
+            },
+            LinkedList::Nil => None,
+        }
+    }
+
+    /// Determine if any item in the list is equal to `item`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    ///
+    /// assert!(list.contains(&2));
+    /// assert!(!list.contains(&4));
+    /// ```
+    pub fn contains(&self, item: &T) -> bool where T: PartialEq {
+        self.position(item).is_some()
+    }
+
+    /// Return the index of the first item in the list equal to `item`, or
+    /// `None` if there is no such item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(2).push(3).push(2).push(1);
+    ///
+    /// assert_eq!(list.position(&2), Some(1));
+    /// assert_eq!(list.position(&4), None);
+    /// ```
+    pub fn position(&self, item: &T) -> Option<usize> where T: PartialEq {
+        self.into_iter().position(|i| i == item)
+    }
+
+    /// Return the first item which is equal to an item earlier in the list,
+    /// or `None` when every item is distinct. The items seen so far are
+    /// kept in a `HashSet`, so the list is only walked once.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(2).push(3).push(2).push(1);
+    ///
+    /// assert_eq!(list.first_duplicate(), Some(&2));
+    /// ```
+    pub fn first_duplicate(&self) -> Option<&T> where T: Eq + Hash {
+        let mut seen = HashSet::new();
+        self.into_iter().find(|&i| !seen.insert(i))
+    }
+
     /// Returns the list with an item inserted at the given index. Indexing
     /// starts at zero. For example `(Cons 7 (Cons 9 (Cons 3)))` has `7` at
     /// index 0, `9` at 1, and `3` at 2. Elements in the list after the
@@ -179,7 +311,49 @@ impl<'a, T> LinkedList<T> {
         }
     }
 
-
+    /// Returns the list with every item of `items` inserted starting at the
+    /// given index, in the order the iterator yields them. This is the bulk
+    /// version of `insert`, and follows the same indexing rules. The items
+    /// are collected first, since the list is built from the back.
+    ///
+    /// This function returns a `Result` to handle the case when `index` is
+    /// out of bounds. In this case, the original list is returned in the
+    /// `Err` to allow for it's continued use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list_a = LinkedList::new().push(3)
+    ///                               .push(0)
+    ///                               .insert_many(1, vec![1, 2])
+    ///                               .unwrap();
+    /// let list_b = LinkedList::new().push(3)
+    ///                               .push(2)
+    ///                               .push(1)
+    ///                               .push(0);
+    ///
+    /// assert_eq!(list_a, list_b);
+    /// ```
+    pub fn insert_many<I: IntoIterator<Item=T>>(self, index: usize, items: I) -> MoveResult<LinkedList<T>, T> {
+        if index == 0 {
+            let items = items.into_iter().collect::<Vec<_>>();
+            Ok(items.into_iter().rev().fold(self, LinkedList::push))
+        } else {
+            match self {
+                LinkedList::Cons(i, r) => {
+                    match r.insert_many(index - 1, items) {
+                        Ok(l) => Ok(LinkedList::Cons(i, Box::new(l))),
+                        Err(l) => Err(LinkedList::Cons(i, Box::new(l))),
+                    }
+                },
+                LinkedList::Nil => {
+                    Err(self)
+                },
+            }
+        }
+    }
 
     /// Removes an item by index in the list. Like `pop`, both the item and
     /// the rest of the list are moved and returned. Indexing starts at 0, see
@@ -221,6 +395,397 @@ impl<'a, T> LinkedList<T> {
             },
         }
     }
+
+    /// Returns the list with `other` joined onto its end. The order of the
+    /// items in both lists is preserved, with the items of `self` first.
+    /// This walks to the `Nil` at the end of `self`, rebuilding the spine
+    /// along the way, so it takes time linear in the length of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list_a = LinkedList::new().push(2).push(1);
+    /// let list_b = LinkedList::new().push(4).push(3);
+    ///
+    /// assert_eq!(list_a.append(list_b), LinkedList::new().push(4)
+    ///                                                     .push(3)
+    ///                                                     .push(2)
+    ///                                                     .push(1));
+    /// ```
+    pub fn append(self, other: LinkedList<T>) -> LinkedList<T> {
+        match self {
+            LinkedList::Cons(i, r) => LinkedList::Cons(i, Box::new(r.append(other))),
+            LinkedList::Nil => other,
+        }
+    }
+
+    /// Split the list into the first `index` items and the rest. Both lists
+    /// keep the original order of their items. Splitting at 0 gives an empty
+    /// first list, and splitting at the length of the list gives an empty
+    /// second list.
+    ///
+    /// This function returns a `Result` to handle the case when `index` is
+    /// out of bounds. In this case, the original list is returned in the
+    /// `Err` to allow for it's continued use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    /// let (front, back) = list.split_at(1).unwrap();
+    ///
+    /// assert_eq!(front, LinkedList::new().push(1));
+    /// assert_eq!(back, LinkedList::new().push(3).push(2));
+    /// ```
+    pub fn split_at(self, index: usize) -> MoveResult<(LinkedList<T>, LinkedList<T>), T> {
+        if index == 0 {
+            Ok((LinkedList::Nil, self))
+        } else {
+            match self {
+                LinkedList::Cons(i, r) => {
+                    match r.split_at(index - 1) {
+                        Ok((front, back)) => Ok((LinkedList::Cons(i, Box::new(front)), back)),
+                        Err(l) => Err(LinkedList::Cons(i, Box::new(l))),
+                    }
+                },
+                LinkedList::Nil => {
+                    Err(self)
+                },
+            }
+        }
+    }
+
+    /// Split the list just before the first item equal to `target`. The
+    /// first list holds everything before it, and the second holds `target`
+    /// and everything after. If `target` isn't in the list, the whole list
+    /// is returned first and the second list is empty.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    /// let (front, back) = list.split_at_value(&2);
+    ///
+    /// assert_eq!(front, LinkedList::new().push(1));
+    /// assert_eq!(back, LinkedList::new().push(3).push(2));
+    /// ```
+    pub fn split_at_value(self, target: &T) -> (LinkedList<T>, LinkedList<T>) where T: PartialEq {
+        match self {
+            LinkedList::Cons(i, r) => {
+                if i == *target {
+                    (LinkedList::Nil, LinkedList::Cons(i, r))
+                } else {
+                    let (front, back) = r.split_at_value(target);
+                    (LinkedList::Cons(i, Box::new(front)), back)
+                }
+            },
+            LinkedList::Nil => {
+                (LinkedList::Nil, LinkedList::Nil)
+            },
+        }
+    }
+
+    /// Returns the list with the order of its items reversed. Items are
+    /// popped off the front of `self` and pushed onto a new list one at a
+    /// time, so this runs in linear time without any recursion.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    ///
+    /// assert_eq!(list.reverse(), LinkedList::new().push(1).push(2).push(3));
+    /// ```
+    pub fn reverse(self) -> LinkedList<T> {
+        self.into_iter().fold(LinkedList::new(), LinkedList::push)
+    }
+
+    /// Removes an item by index in the list by moving the first item of the
+    /// list into its place. Both the removed item and the rest of the list
+    /// are moved and returned. Indexing starts at 0, see `insert` for an
+    /// example of the indexing.
+    ///
+    /// Unlike `remove`, the order of the persisted elements is **not**
+    /// preserved, since the old head now sits where the removed item was.
+    /// In exchange the list after `index` is left untouched.
+    ///
+    /// This function returns a `Result` to handle the case when `index` is
+    /// out of bounds. In this case, the original list is returned in the
+    /// `Err` to allow for it's continued use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let (item, list) = LinkedList::new().push(4)
+    ///                                     .push(3)
+    ///                                     .push(2)
+    ///                                     .push(1)
+    ///                                     .swap_remove(2)
+    ///                                     .unwrap();
+    ///
+    /// assert_eq!(item, 3);
+    /// assert_eq!(list, LinkedList::new().push(4).push(1).push(2));
+    /// ```
+    pub fn swap_remove(self, index: usize) -> MoveResult<(T, LinkedList<T>), T> {
+        match self {
+            LinkedList::Cons(head, mut rest) => {
+                if index == 0 {
+                    Ok((head, *rest))
+                } else {
+                    match rest.replace(index - 1, head) {
+                        Ok(item) => Ok((item, *rest)),
+                        Err(head) => Err(LinkedList::Cons(head, rest)),
+                    }
+                }
+            },
+            LinkedList::Nil => {
+                Err(self)
+            },
+        }
+    }
+
+    /// Returns the list with its items sorted, using a merge sort. The list
+    /// is split in half with `split_at`, each half is sorted recursively,
+    /// and the sorted halves are combined with `merge_sorted`. The sort is
+    /// stable, equal items keep their original order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(2).push(1).push(3);
+    ///
+    /// assert_eq!(list.sort(), LinkedList::new().push(3).push(2).push(1));
+    /// ```
+    pub fn sort(self) -> LinkedList<T> where T: Ord {
+        let len = self.len();
+        if len < 2 {
+            return self;
+        }
+        match self.split_at(len / 2) {
+            Ok((front, back)) => front.sort().merge_sorted(back.sort()),
+            // Half the length is always in bounds.
+            Err(list) => list,
+        }
+    }
+
+    /// Move the item at `index` to the front of the list, keeping the
+    /// relative order of every other item. This is the core step of
+    /// move-to-front heuristics, like those used by LRU caches.
+    ///
+    /// This function returns a `Result` to handle the case when `index` is
+    /// out of bounds. In this case, the original list is returned in the
+    /// `Err` to allow for it's continued use.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3)
+    ///                             .push(2)
+    ///                             .push(1)
+    ///                             .bring_to_front(2)
+    ///                             .unwrap();
+    ///
+    /// assert_eq!(list, LinkedList::new().push(2).push(1).push(3));
+    /// ```
+    pub fn bring_to_front(self, index: usize) -> MoveResult<LinkedList<T>, T> {
+        self.remove(index).map(|(item, rest)| rest.push(item))
+    }
+
+    /// Apply `f` to every item of the list, returning a list of the results
+    /// in the same order. The list is consumed, so `f` takes each item by
+    /// value and may change its type.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    ///
+    /// assert_eq!(list.map(|i| i * 10), LinkedList::new().push(30)
+    ///                                                   .push(20)
+    ///                                                   .push(10));
+    /// ```
+    pub fn map<U, F: FnMut(T) -> U>(self, f: F) -> LinkedList<U> {
+        self.into_iter().map(f).collect()
+    }
+
+    /// Keep every `n`th item of the list starting with the first, so indexes
+    /// 0, `n`, `2n` and so on, preserving their order. This is the list
+    /// version of `Iterator::step_by`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is 0.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(4).push(3).push(2).push(1).push(0);
+    ///
+    /// assert_eq!(list.step_by(2), LinkedList::new().push(4).push(2).push(0));
+    /// ```
+    pub fn step_by(self, n: usize) -> LinkedList<T> {
+        assert!(n != 0, "step_by requires a step of at least 1");
+        self.into_iter().step_by(n).collect()
+    }
+
+    /// Apply `f` to every pair of adjacent items, returning a list of the
+    /// results in order. A list of length `n` gives `n - 1` results, and
+    /// lists with fewer than two items give an empty list. This is handy for
+    /// computing differences or transitions between consecutive items.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(10).push(6).push(1);
+    /// let deltas = list.map_pairs(|a, b| b - a);
+    ///
+    /// assert_eq!(deltas, LinkedList::new().push(4).push(5));
+    /// ```
+    pub fn map_pairs<U, F: FnMut(&T, &T) -> U>(&self, mut f: F) -> LinkedList<U> {
+        self.into_iter()
+            .zip(self.into_iter().skip(1))
+            .map(|(a, b)| f(a, b))
+            .collect()
+    }
+
+    /// Copy the items of the list into a new `Vec`, in list order. The list
+    /// itself is only borrowed, so each item is cloned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    ///
+    /// assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn to_vec(&self) -> Vec<T> where T: Clone {
+        self.into_iter().cloned().collect()
+    }
+
+    /// Collect references to every item of the list into a `Vec`, in list
+    /// order. Indexing a list is linear in the index, while indexing the
+    /// returned `Vec` is constant, which helps algorithms needing many
+    /// random accesses. The `Vec` is a snapshot which borrows the list, so
+    /// the list can't be changed while it's alive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list = LinkedList::new().push(3).push(2).push(1);
+    /// let refs = list.to_vec_refs();
+    ///
+    /// assert_eq!(*refs[2], 3);
+    /// ```
+    pub fn to_vec_refs(&self) -> Vec<&T> {
+        self.into_iter().collect()
+    }
+
+    /// Build a binary search tree from the items of the list, pushing each
+    /// one into the tree in list order. Reading the tree back in order gives
+    /// the items sorted, so this doubles as a simple way to sort a list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    /// use structures::tree::BinaryTree;
+    ///
+    /// let list = LinkedList::new().push(1).push(3).push(2);
+    ///
+    /// assert_eq!(list.into_binary_tree(), BinaryTree::new().push(2).push(3).push(1));
+    /// ```
+    pub fn into_binary_tree(self) -> BinaryTree<T> where T: PartialEq + PartialOrd + Clone {
+        self.into_iter().fold(BinaryTree::new(), BinaryTree::push)
+    }
+
+    // Replace the item at `index` with `item` in place, returning the old
+    // item, or giving `item` back when `index` is out of bounds.
+    fn replace(&mut self, index: usize, item: T) -> Result<T, T> {
+        match self.get_mut(index) {
+            Some(i) => Ok(mem::replace(i, item)),
+            None => Err(item),
+        }
+    }
+
+    /// Merge two already sorted lists into a single sorted list. This walks
+    /// both lists once, always taking the smaller head, so it runs in
+    /// O(n + m) without re-sorting anything. When heads are equal the item
+    /// from `self` comes first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use structures::list::LinkedList;
+    ///
+    /// let list_a = LinkedList::new().push(5).push(3).push(1);
+    /// let list_b = LinkedList::new().push(6).push(4).push(2);
+    /// let merged = list_a.merge_sorted(list_b);
+    ///
+    /// assert_eq!(merged.into_iter().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6]);
+    /// ```
+    pub fn merge_sorted(self, other: LinkedList<T>) -> LinkedList<T> where T: Ord {
+        match (self, other) {
+            (LinkedList::Cons(a, ra), LinkedList::Cons(b, rb)) => {
+                if a <= b {
+                    LinkedList::Cons(a, Box::new(ra.merge_sorted(LinkedList::Cons(b, rb))))
+                } else {
+                    LinkedList::Cons(b, Box::new(LinkedList::Cons(a, ra).merge_sorted(*rb)))
+                }
+            },
+            (list, LinkedList::Nil) | (LinkedList::Nil, list) => list,
+        }
+    }
+}
+
+/// Lists display as their items separated by commas inside square brackets,
+/// much like a `Vec` does with `{:?}`. This is far easier to read than the
+/// nested `Cons` structure printed by `Debug`.
+///
+/// # Examples
+///
+/// ```
+/// use structures::list::LinkedList;
+///
+/// let list = LinkedList::new().push(3).push(2).push(1);
+///
+/// assert_eq!(format!("{}", list), "[1, 2, 3]");
+/// assert_eq!(format!("{}", LinkedList::Nil::<u32>), "[]");
+/// ```
+impl<T: fmt::Display> fmt::Display for LinkedList<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "[")?;
+        for (index, item) in self.into_iter().enumerate() {
+            if index > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", item)?;
+        }
+        write!(f, "]")
+    }
 }
 
 /// This trait allows for creation of a `LinkedList<T>` from any type that
@@ -228,20 +793,46 @@ impl<'a, T> LinkedList<T> {
 /// allows us to make a `List` from anything that is iterable, without
 /// needing to handle different type specially.
 ///
+/// The resulting list iterates in the same order as the source. Since `push`
+/// adds to the front of the list, the items are first collected and then
+/// pushed from last to first.
+///
 /// # Examples
 ///
 /// ```
 /// use std::iter::FromIterator;
 /// use structures::list::LinkedList;
 ///
-/// let list_a = LinkedList::from_iter((0..10));
+/// let list_a = LinkedList::from_iter(0..10);
 /// let list_b = LinkedList::from_iter(vec![0,1,2,3,4,5,6,7,8,9]);
 ///
 /// assert_eq!(list_a, list_b);
+/// assert_eq!(LinkedList::from_iter(0..3), LinkedList::new().push(2)
+///                                                          .push(1)
+///                                                          .push(0));
 /// ```
 impl<T> FromIterator<T> for LinkedList<T> {
     fn from_iter<I: IntoIterator<Item=T>>(iterable: I) -> LinkedList<T> {
-        iterable.into_iter().fold(LinkedList::new(), LinkedList::push)
+        let items = iterable.into_iter().collect::<Vec<_>>();
+        items.into_iter().rev().fold(LinkedList::new(), LinkedList::push)
+    }
+}
+
+/// Lists can be made directly from a `Vec`, keeping the order of its items.
+/// This is the same as calling `from_iter` with the `Vec`.
+///
+/// # Examples
+///
+/// ```
+/// use structures::list::LinkedList;
+///
+/// let list = LinkedList::from(vec![1, 2, 3]);
+///
+/// assert_eq!(list, LinkedList::new().push(3).push(2).push(1));
+/// ```
+impl<T> From<Vec<T>> for LinkedList<T> {
+    fn from(vec: Vec<T>) -> LinkedList<T> {
+        LinkedList::from_iter(vec)
     }
 }
 
@@ -278,6 +869,36 @@ impl<'a, T> IntoIterator for &'a LinkedList<T> {
     }
 }
 
+/// This trait, implemented for a mutable reference to a `List` allows, that
+/// reference to be treated as an iterator by calling `into_iter()` on it.
+/// This effectively allows a mutable reference to a linked list to be used
+/// as an iterator anywhere that accepts `IntoIterator<Item=&'a mut T>`.
+///
+/// This trait implementation yields `IterMut`s which iterate over mutable
+/// references, allowing each item to be changed in place.
+///
+/// # Examples
+///
+/// ```
+/// use structures::list::LinkedList;
+///
+/// let mut list = LinkedList::new().push(3).push(2).push(1);
+///
+/// for i in &mut list {
+///     *i += 1;
+/// }
+///
+/// assert_eq!(list, LinkedList::new().push(4).push(3).push(2));
+/// ```
+impl<'a, T> IntoIterator for &'a mut LinkedList<T> {
+    type Item = &'a mut T;
+    type IntoIter = IterMut<'a, T>;
+
+    fn into_iter(self) -> IterMut<'a, T> {
+        IterMut { current: Some(self) }
+    }
+}
+
 /// This trait, implemented for a reference to a `List` allows, that
 /// reference to be treated as an iterator by calling `into_iter()` on it.
 /// This effectively allows a reference to a linked list to be used as an
@@ -321,19 +942,51 @@ impl<T> Iterator for IntoIter<T> {
             item
         })
     }
+
+    // The list doesn't store its length, so this walks the rest of it.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.current.len();
+        (len, Some(len))
+    }
 }
 
+impl<T> ExactSizeIterator for IntoIter<T> {}
+
 impl<'a, T> Iterator for Iter<'a, T> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<&'a T> {
-        match self.current {
-            // TODO: Revisit ref.
-            &LinkedList::Cons(ref val, ref next) => {
+        match *self.current {
+            LinkedList::Cons(ref val, ref next) => {
                 self.current = next;
                 Some(val)
             },
-            &LinkedList::Nil => {
+            LinkedList::Nil => {
+                None
+            },
+        }
+    }
+
+    // The list doesn't store its length, so this walks the rest of it.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.current.len();
+        (len, Some(len))
+    }
+}
+
+impl<'a, T> ExactSizeIterator for Iter<'a, T> {}
+
+impl<'a, T> Iterator for IterMut<'a, T> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<&'a mut T> {
+        // Take the current list out so its parts can be borrowed for `'a`.
+        match self.current.take() {
+            Some(&mut LinkedList::Cons(ref mut val, ref mut next)) => {
+                self.current = Some(next);
+                Some(val)
+            },
+            _ => {
                 None
             },
         }
@@ -342,7 +995,9 @@ impl<'a, T> Iterator for Iter<'a, T> {
 
 #[cfg(test)]
 mod test {
+    use std::cmp::Ordering;
     use std::iter::FromIterator;
+    use tree::BinaryTree;
     use super::LinkedList;
 
     #[test]
@@ -366,6 +1021,16 @@ mod test {
         assert_eq!(len_list.len(), 3);
     }
 
+    #[test]
+    fn test_cmp_len() {
+        let short = LinkedList::new().push(1);
+        let long = LinkedList::from_iter(0..100);
+        assert_eq!(short.cmp_len(&long), Ordering::Less);
+        assert_eq!(long.cmp_len(&short), Ordering::Greater);
+        assert_eq!(long.cmp_len(&LinkedList::from_iter(100..200)), Ordering::Equal);
+        assert_eq!(LinkedList::new().cmp_len(&LinkedList::Nil::<u32>), Ordering::Equal);
+    }
+
     #[test]
     fn test_push() {
         let push_list = LinkedList::new().push(1).push(2);
@@ -381,6 +1046,66 @@ mod test {
         assert_eq!(pop_list, list);
     }
 
+    #[test]
+    fn test_get() {
+        let list = LinkedList::new().push(3).push(2).push(1);
+        assert_eq!(list.get(0), Some(&1));
+        assert_eq!(list.get(1), Some(&2));
+        assert_eq!(list.get(2), Some(&3));
+    }
+
+    #[test]
+    fn test_get_out_of_bounds() {
+        let list = LinkedList::new().push(3).push(2).push(1);
+        assert_eq!(list.get(3), None);
+        assert_eq!(LinkedList::<u32>::new().get(0), None);
+    }
+
+    #[test]
+    fn test_get_mut() {
+        let mut list = LinkedList::new().push(3).push(2).push(1);
+        *list.get_mut(1).unwrap() += 40;
+        assert_eq!(list.get(1), Some(&42));
+        assert_eq!(Vec::from_iter(list), vec![1, 42, 3]);
+    }
+
+    #[test]
+    fn test_get_mut_out_of_bounds() {
+        let mut list = LinkedList::new().push(1);
+        assert_eq!(list.get_mut(1), None);
+    }
+
+    #[test]
+    fn test_contains() {
+        let list = LinkedList::from(vec![1, 2, 3]);
+        assert!(list.contains(&1));
+        assert!(list.contains(&3));
+        assert!(!list.contains(&4));
+    }
+
+    #[test]
+    fn test_position() {
+        let list = LinkedList::from(vec![1, 2, 3, 2]);
+        assert_eq!(list.position(&3), Some(2));
+        assert_eq!(list.position(&2), Some(1));
+        assert_eq!(list.position(&4), None);
+    }
+
+    #[test]
+    fn test_first_duplicate() {
+        let list = LinkedList::from(vec![1, 1, 2, 3, 2]);
+        assert_eq!(list.first_duplicate(), Some(&1));
+        let list = LinkedList::from(vec![1, 2, 3, 4, 3, 2]);
+        assert_eq!(list.first_duplicate(), Some(&3));
+    }
+
+    #[test]
+    fn test_first_duplicate_distinct() {
+        let list = LinkedList::from(vec![1, 2, 3, 4]);
+        assert_eq!(list.first_duplicate(), None);
+        assert_eq!(LinkedList::<u32>::new().first_duplicate(), None);
+    }
+
     #[test]
     fn test_insert_in_bounds() {
         let list = LinkedList::new().push(4).push(3).push(1).insert(1, 2).unwrap();
@@ -399,6 +1124,30 @@ mod test {
         assert_eq!(list, LinkedList::new().push(2));
     }
 
+    #[test]
+    fn test_insert_many_front() {
+        let list = LinkedList::new().push(4).push(3).insert_many(0, 0..3).unwrap();
+        assert_eq!(Vec::from_iter(list), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_insert_many_middle() {
+        let list = LinkedList::new().push(4).push(0).insert_many(1, 1..4).unwrap();
+        assert_eq!(Vec::from_iter(list), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_insert_many_end() {
+        let list = LinkedList::new().push(1).push(0).insert_many(2, 2..5).unwrap();
+        assert_eq!(Vec::from_iter(list), vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_insert_many_out_of_bounds() {
+        let list = LinkedList::new().push(2).insert_many(2, 0..3).unwrap_err();
+        assert_eq!(list, LinkedList::new().push(2));
+    }
+
     #[test]
     fn test_remove_in_bounds() {
         let (item, list) = LinkedList::new().push(4).push(3).push(2)
@@ -421,8 +1170,223 @@ mod test {
         assert_eq!(list, LinkedList::new().push(2));
     }
 
-    // #[test]
-    // fn test_append() {}
+    #[test]
+    fn test_split_at_zero() {
+        let (front, back) = LinkedList::from(vec![1, 2, 3]).split_at(0).unwrap();
+        assert_eq!(front, LinkedList::Nil);
+        assert_eq!(back.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_split_at_in_bounds() {
+        let (front, back) = LinkedList::from(vec![1, 2, 3, 4]).split_at(2).unwrap();
+        assert_eq!(front.to_vec(), vec![1, 2]);
+        assert_eq!(back.to_vec(), vec![3, 4]);
+    }
+
+    #[test]
+    fn test_split_at_bounds() {
+        let (front, back) = LinkedList::from(vec![1, 2, 3]).split_at(3).unwrap();
+        assert_eq!(front.to_vec(), vec![1, 2, 3]);
+        assert_eq!(back, LinkedList::Nil);
+    }
+
+    #[test]
+    fn test_split_at_out_of_bounds() {
+        let list = LinkedList::from(vec![1, 2, 3]).split_at(4).unwrap_err();
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_split_at_value_front() {
+        let (front, back) = LinkedList::from(vec![1, 2, 3]).split_at_value(&1);
+        assert_eq!(front, LinkedList::Nil);
+        assert_eq!(back.to_vec(), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_split_at_value_middle() {
+        let (front, back) = LinkedList::from(vec![1, 2, 3, 2]).split_at_value(&2);
+        assert_eq!(front.to_vec(), vec![1]);
+        assert_eq!(back.to_vec(), vec![2, 3, 2]);
+    }
+
+    #[test]
+    fn test_split_at_value_absent() {
+        let (front, back) = LinkedList::from(vec![1, 2, 3]).split_at_value(&4);
+        assert_eq!(front.to_vec(), vec![1, 2, 3]);
+        assert_eq!(back, LinkedList::Nil);
+    }
+
+    #[test]
+    fn test_reverse() {
+        let list = LinkedList::from_iter(0..5).reverse();
+        assert_eq!(Vec::from_iter(list), vec![4, 3, 2, 1, 0]);
+        let list: LinkedList<u32> = LinkedList::new();
+        assert_eq!(list.reverse(), LinkedList::Nil);
+    }
+
+    #[test]
+    fn test_swap_remove_head() {
+        let (item, list) = LinkedList::new().push(3).push(2).push(1).swap_remove(0).unwrap();
+        assert_eq!(item, 1);
+        assert_eq!(Vec::from_iter(list), vec![2, 3]);
+    }
+
+    #[test]
+    fn test_swap_remove_middle() {
+        let (item, list) = LinkedList::new().push(4).push(3).push(2).push(1).swap_remove(2).unwrap();
+        assert_eq!(item, 3);
+        assert_eq!(Vec::from_iter(list), vec![2, 1, 4]);
+    }
+
+    #[test]
+    fn test_swap_remove_out_of_bounds() {
+        let list = LinkedList::new().push(2).push(1).swap_remove(2).unwrap_err();
+        assert_eq!(list, LinkedList::new().push(2).push(1));
+    }
+
+    #[test]
+    fn test_bring_to_front_middle() {
+        let list = LinkedList::new().push(4).push(3).push(2).push(1).bring_to_front(2).unwrap();
+        assert_eq!(Vec::from_iter(list), vec![3, 1, 2, 4]);
+    }
+
+    #[test]
+    fn test_bring_to_front_last() {
+        let list = LinkedList::new().push(4).push(3).push(2).push(1).bring_to_front(3).unwrap();
+        assert_eq!(Vec::from_iter(list), vec![4, 1, 2, 3]);
+    }
+
+    #[test]
+    fn test_bring_to_front_out_of_bounds() {
+        let list = LinkedList::new().push(2).push(1).bring_to_front(2).unwrap_err();
+        assert_eq!(list, LinkedList::new().push(2).push(1));
+    }
+
+    #[test]
+    fn test_map() {
+        let list = LinkedList::from(vec![1, 2, 3]).map(|i| i * i);
+        assert_eq!(list.to_vec(), vec![1, 4, 9]);
+        let list = LinkedList::from(vec![1, 2, 3]).map(|i| i.to_string());
+        assert_eq!(list.to_vec(), vec!["1", "2", "3"]);
+    }
+
+    #[test]
+    fn test_step_by() {
+        let list = LinkedList::from_iter(0..5);
+        assert_eq!(list.clone().step_by(1), list);
+        assert_eq!(list.clone().step_by(2).to_vec(), vec![0, 2, 4]);
+        assert_eq!(list.step_by(10).to_vec(), vec![0]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_step_by_zero() {
+        LinkedList::from_iter(0..5).step_by(0);
+    }
+
+    #[test]
+    fn test_map_pairs() {
+        let list = LinkedList::new().push(10).push(6).push(3).push(1);
+        let deltas = list.map_pairs(|a, b| b - a);
+        assert_eq!(Vec::from_iter(deltas), vec![2, 3, 4]);
+    }
+
+    #[test]
+    fn test_map_pairs_short() {
+        let list = LinkedList::new().push(1);
+        assert_eq!(list.map_pairs(|a, b| a + b), LinkedList::Nil);
+        let list: LinkedList<u32> = LinkedList::new();
+        assert_eq!(list.map_pairs(|a, b| a + b), LinkedList::Nil);
+    }
+
+    #[test]
+    fn test_to_vec() {
+        let list = LinkedList::from(vec![1, 2, 3]);
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        assert_eq!(LinkedList::from(Vec::<u32>::new()).to_vec(), vec![]);
+    }
+
+    #[test]
+    fn test_to_vec_refs() {
+        let list = LinkedList::from(vec![4, 8, 15, 16]);
+        let refs = list.to_vec_refs();
+        assert_eq!(refs.len(), 4);
+        for (a, b) in refs.iter().zip(&list) {
+            assert!(::std::ptr::eq(*a, b));
+        }
+        assert_eq!(*refs[3], 16);
+    }
+
+    #[test]
+    fn test_into_binary_tree() {
+        let tree = LinkedList::new().push(4).push(1).push(5).push(3).into_binary_tree();
+        let leaf = || Box::new(BinaryTree::Leaf);
+        let expected = BinaryTree::Node(3,
+            Box::new(BinaryTree::Node(1, leaf(), leaf())),
+            Box::new(BinaryTree::Node(5, Box::new(BinaryTree::Node(4, leaf(), leaf())), leaf())));
+        assert_eq!(tree, expected);
+        assert_eq!(tree.flatten(), vec![1, 3, 4, 5]);
+    }
+
+    #[test]
+    fn test_sort() {
+        let list = LinkedList::from(vec![3, 1, 2]).sort();
+        assert_eq!(list.to_vec(), vec![1, 2, 3]);
+        let list = LinkedList::from(vec![5, 1, 4, 1, 5, 9, 2, 6]).sort();
+        assert_eq!(list.to_vec(), vec![1, 1, 2, 4, 5, 5, 6, 9]);
+    }
+
+    #[test]
+    fn test_sort_short() {
+        assert_eq!(LinkedList::<u32>::new().sort(), LinkedList::Nil);
+        assert_eq!(LinkedList::new().push(1).sort(), LinkedList::new().push(1));
+    }
+
+    #[test]
+    fn test_merge_sorted() {
+        let list_a = LinkedList::new().push(7).push(4).push(1);
+        let list_b = LinkedList::new().push(9).push(5).push(3).push(2);
+        let list = list_a.merge_sorted(list_b);
+        assert_eq!(Vec::from_iter(list), vec![1, 2, 3, 4, 5, 7, 9]);
+    }
+
+    #[test]
+    fn test_merge_sorted_empty() {
+        let list = LinkedList::new().merge_sorted(LinkedList::new().push(2).push(1));
+        assert_eq!(Vec::from_iter(list), vec![1, 2]);
+        let list = LinkedList::new().push(2).push(1).merge_sorted(LinkedList::new());
+        assert_eq!(Vec::from_iter(list), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_merge_sorted_duplicates() {
+        let list_a = LinkedList::new().push(3).push(2).push(1);
+        let list_b = LinkedList::new().push(3).push(2).push(2);
+        let list = list_a.merge_sorted(list_b);
+        assert_eq!(Vec::from_iter(list), vec![1, 2, 2, 2, 3, 3]);
+    }
+
+    #[test]
+    fn test_append() {
+        let list = LinkedList::new().push(2).push(1).append(LinkedList::new().push(4).push(3));
+        assert_eq!(Vec::from_iter(list), vec![1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn test_append_nil() {
+        let list = LinkedList::new().append(LinkedList::new().push(2).push(1));
+        assert_eq!(list, LinkedList::new().push(2).push(1));
+        let list = LinkedList::new().push(2).push(1).append(LinkedList::new());
+        assert_eq!(list, LinkedList::new().push(2).push(1));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", LinkedList::from_iter(1..4)), "[1, 2, 3]");
+        assert_eq!(format!("{}", LinkedList::Nil::<u32>), "[]");
+    }
 
     #[test]
     fn test_iter() {
@@ -432,9 +1396,48 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_iter_mut() {
+        let mut list = LinkedList::from(vec![1, 2, 3]);
+        for i in &mut list {
+            *i *= 2;
+        }
+        assert_eq!((&list).into_iter().collect::<Vec<_>>(), vec![&2, &4, &6]);
+    }
+
+    #[test]
+    fn test_iter_len() {
+        let list = LinkedList::from(vec![1, 2, 3]);
+        assert_eq!((&list).into_iter().len(), list.len());
+        let mut iter = (&list).into_iter();
+        assert_eq!(iter.size_hint(), (3, Some(3)));
+        iter.next();
+        assert_eq!(iter.size_hint(), (2, Some(2)));
+    }
+
+    #[test]
+    fn test_into_iter_len() {
+        let mut iter = LinkedList::from(vec![1, 2, 3]).into_iter();
+        assert_eq!(iter.len(), 3);
+        iter.next();
+        iter.next();
+        assert_eq!(iter.size_hint(), (1, Some(1)));
+        iter.next();
+        assert_eq!(iter.len(), 0);
+    }
+
     #[test]
     fn test_into_iter() {
         let list: LinkedList<u32> = LinkedList::new().push(1).push(2).push(3);
         assert_eq!(Vec::from_iter(list), vec![3, 2, 1]);
+        let list = LinkedList::from_iter(vec![1, 2, 3]);
+        assert_eq!(Vec::from_iter(list), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let list = LinkedList::from_iter(0..3);
+        assert_eq!(list, LinkedList::new().push(2).push(1).push(0));
+        assert_eq!(LinkedList::from_iter(Vec::<u32>::new()), LinkedList::Nil);
     }
 }
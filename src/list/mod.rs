@@ -16,7 +16,7 @@
 //! use structures::list::LinkedList;
 //!
 //! // Easily create a linked list.
-//! let list = LinkedList::from_iter((0..100));
+//! let list = LinkedList::from_iter(0..100);
 //! assert_eq!(list.len(), 100);
 //! ```
 
@@ -1,4 +1,7 @@
-use std::ops::Add;
+use std::collections::VecDeque;
+use std::iter::FromIterator;
+use std::ops::{Add, Mul, Sub};
+use std::slice;
 
 macro_rules! maybe {
     ($expr:expr) => (match $expr {
@@ -7,17 +10,81 @@ macro_rules! maybe {
     })
 }
 
-#[derive(Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-pub enum BinaryTree<T: PartialEq + PartialOrd + Copy> {
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum BinaryTree<T: PartialEq + PartialOrd + Clone> {
     Node(T, Box<BinaryTree<T>>, Box<BinaryTree<T>>),
+    #[default]
     Leaf,
 }
 
-impl<T: PartialEq + PartialOrd + Copy> BinaryTree<T> {
+/// In-order iterator for trees by reference.
+pub struct Iter<'a, T: 'a + PartialEq + PartialOrd + Clone> {
+    stack: Vec<(&'a T, &'a BinaryTree<T>)>,
+}
+
+/// In-order iterator for trees by value.
+pub struct IntoIter<T: PartialEq + PartialOrd + Clone> {
+    stack: Vec<(T, BinaryTree<T>)>,
+}
+
+impl<T: PartialEq + PartialOrd + Clone> BinaryTree<T> {
     pub fn new() -> BinaryTree<T> {
         BinaryTree::Leaf
     }
 
+    /// Determine if the tree is empty.
+    pub fn is_empty(&self) -> bool {
+        match *self {
+            BinaryTree::Node(_, _, _) => false,
+            BinaryTree::Leaf => true,
+        }
+    }
+
+    /// The number of items in the tree.
+    pub fn len(&self) -> usize {
+        match *self {
+            BinaryTree::Node(_, ref l, ref r) => 1 + l.len() + r.len(),
+            BinaryTree::Leaf => 0,
+        }
+    }
+
+    /// The number of edges on the longest path from the root down to a node
+    /// with no children. Both an empty tree and a single node have height 0.
+    pub fn height(&self) -> usize {
+        self.depth().saturating_sub(1)
+    }
+
+    // The number of nodes on the longest path from the root down, so an
+    // empty tree has depth 0 and a single node depth 1. Unlike `height`
+    // this tells an empty subtree apart from a single node, which is what
+    // balance factors need.
+    fn depth(&self) -> usize {
+        match *self {
+            BinaryTree::Node(_, ref l, ref r) => 1 + l.depth().max(r.depth()),
+            BinaryTree::Leaf => 0,
+        }
+    }
+
+    /// Rebuild a tree from its pre-order and in-order traversals. The shape
+    /// is taken entirely from the traversals, so the result need not be a
+    /// search tree. Returns `None` when the two sequences are inconsistent.
+    /// Values are assumed to be distinct.
+    pub fn from_traversals(preorder: &[T], inorder: &[T]) -> Option<BinaryTree<T>> {
+        if preorder.len() != inorder.len() {
+            return None;
+        }
+        match preorder.split_first() {
+            Some((item, rest)) => {
+                let index = maybe!(inorder.iter().position(|i| i == item));
+                let (left_pre, right_pre) = rest.split_at(index);
+                let left = maybe!(BinaryTree::from_traversals(left_pre, &inorder[..index]));
+                let right = maybe!(BinaryTree::from_traversals(right_pre, &inorder[index + 1..]));
+                Some(BinaryTree::Node(item.clone(), Box::new(left), Box::new(right)))
+            },
+            None => Some(BinaryTree::Leaf),
+        }
+    }
+
     pub fn push(self, item: T) -> BinaryTree<T> {
         match self {
             BinaryTree::Node(i, l, r) => {
@@ -33,23 +100,95 @@ impl<T: PartialEq + PartialOrd + Copy> BinaryTree<T> {
         }
     }
 
-    // pub fn pop_first(self) -> Option<(T, BinaryTree<T>)> {
-    //     match self {
-    //         BinaryTree::Node(i, l, r) => {
-    //             if l.count() > 0 {
-    //                 let (item, popped) = l.pop_first().unwrap();
-    //                 Some(item, )
-    //             }
-    //         },
-    //         BinaryTree::Leaf => None
-    //     }
-    // }
+    /// Determine if the tree holds an item equal to `item`. Only the path a
+    /// search for `item` takes is visited, so this is proportional to the
+    /// height of the tree.
+    pub fn contains(&self, item: &T) -> bool {
+        match *self {
+            BinaryTree::Node(ref i, ref l, ref r) => {
+                if *item == *i {
+                    true
+                } else if *item > *i {
+                    r.contains(item)
+                } else {
+                    l.contains(item)
+                }
+            },
+            BinaryTree::Leaf => false,
+        }
+    }
+
+    /// The smallest item in the tree, found at the leftmost node, or `None`
+    /// if the tree is empty. This isn't named `min`, since that would be
+    /// shadowed by `Ord::min` when comparing whole trees.
+    pub fn minimum(&self) -> Option<&T> {
+        match *self {
+            BinaryTree::Node(ref i, ref l, _) => l.minimum().or(Some(i)),
+            BinaryTree::Leaf => None,
+        }
+    }
+
+    /// The largest item in the tree, found at the rightmost node, or `None`
+    /// if the tree is empty.
+    pub fn maximum(&self) -> Option<&T> {
+        match *self {
+            BinaryTree::Node(ref i, _, ref r) => r.maximum().or(Some(i)),
+            BinaryTree::Leaf => None,
+        }
+    }
+
+    /// Remove the smallest item from the tree, returning it along with the
+    /// rest of the tree, or `None` if the tree is empty. The smallest item is
+    /// the leftmost node, whose right subtree takes its place.
+    pub fn pop_first(self) -> Option<(T, BinaryTree<T>)> {
+        match self {
+            BinaryTree::Node(i, l, r) => {
+                match *l {
+                    BinaryTree::Leaf => Some((i, *r)),
+                    left => {
+                        let (item, left) = maybe!(left.pop_first());
+                        Some((item, BinaryTree::Node(i, Box::new(left), r)))
+                    },
+                }
+            },
+            BinaryTree::Leaf => None,
+        }
+    }
 
+    /// Remove the largest item from the tree, returning it along with the
+    /// rest of the tree, or `None` if the tree is empty. The largest item is
+    /// the rightmost node, whose left subtree takes its place.
+    pub fn pop_last(self) -> Option<(T, BinaryTree<T>)> {
+        match self {
+            BinaryTree::Node(i, l, r) => {
+                match *r {
+                    BinaryTree::Leaf => Some((i, *l)),
+                    right => {
+                        let (item, right) = maybe!(right.pop_last());
+                        Some((item, BinaryTree::Node(i, l, Box::new(right))))
+                    },
+                }
+            },
+            BinaryTree::Leaf => None,
+        }
+    }
+
+    /// Remove an item from the tree, returning it along with the rest of the
+    /// tree, or `None` if the item isn't in the tree. A node with two
+    /// children is replaced by its in-order predecessor, the largest item of
+    /// its left subtree, so equal items stay to the left as with `push`.
     pub fn remove(self, item: T) -> Option<(T, Self)> {
         match self {
             BinaryTree::Node(i, l, r) => {
                 if item == i {
-                    Some((i, *l + *r))
+                    match (*l, *r) {
+                        (BinaryTree::Leaf, right) => Some((i, right)),
+                        (left, BinaryTree::Leaf) => Some((i, left)),
+                        (left, right) => {
+                            let (predecessor, left) = maybe!(left.pop_last());
+                            Some((i, BinaryTree::Node(predecessor, Box::new(left), Box::new(right))))
+                        },
+                    }
                 } else if item > i {
                     let (removed, right) = maybe!(r.remove(item));
                     Some((removed, BinaryTree::Node(i, l, Box::new(right))))
@@ -62,45 +201,399 @@ impl<T: PartialEq + PartialOrd + Copy> BinaryTree<T> {
         }
     }
 
-    // pub fn flatten(&self) -> Vec<T> {
-    //     match *self {
-    //         BinaryTree::Node(i, l, r) => {
-    //             let mut flat = l.flatten();
-    //             flat.push(i);
-    //             flat + r.flatten();
-    //             flat
-    //         },
-    //         BinaryTree::Leaf => Vec::new(),
-    //     }
-    // }
-}
+    /// Determine if this tree appears, with exactly the same structure and
+    /// values, somewhere inside `other`. By convention the empty tree is a
+    /// subtree of every tree, including another empty tree.
+    pub fn is_subtree_of(&self, other: &BinaryTree<T>) -> bool {
+        match (self, other) {
+            (BinaryTree::Leaf, _) => true,
+            (_, BinaryTree::Node(_, l, r)) => {
+                self == other || self.is_subtree_of(l) || self.is_subtree_of(r)
+            },
+            (_, BinaryTree::Leaf) => false,
+        }
+    }
 
-impl<T: PartialEq + PartialOrd + Copy> Default for BinaryTree<T> {
-    fn default() -> Self {
-        BinaryTree::Leaf
+    /// Flatten the tree into its pre-order sequence, with `None` marking
+    /// each empty child. Unlike a plain traversal this captures the exact
+    /// shape, so `deserialize` can rebuild the same tree.
+    pub fn serialize(&self) -> Vec<Option<T>> {
+        match *self {
+            BinaryTree::Node(ref i, ref l, ref r) => {
+                let mut data = vec![Some(i.clone())];
+                data.extend(l.serialize());
+                data.extend(r.serialize());
+                data
+            },
+            BinaryTree::Leaf => vec![None],
+        }
+    }
+
+    /// Rebuild a tree from the output of `serialize`. Missing trailing
+    /// entries are read as empty children, and anything left over once the
+    /// tree is complete is ignored.
+    pub fn deserialize(data: &[Option<T>]) -> BinaryTree<T> {
+        BinaryTree::deserialize_from(&mut data.iter())
+    }
+
+    fn deserialize_from(data: &mut slice::Iter<Option<T>>) -> BinaryTree<T> {
+        match data.next() {
+            Some(Some(item)) => {
+                let left = BinaryTree::deserialize_from(data);
+                let right = BinaryTree::deserialize_from(data);
+                BinaryTree::Node(item.clone(), Box::new(left), Box::new(right))
+            },
+            _ => BinaryTree::Leaf,
+        }
+    }
+
+    /// Rearrange the tree into a right leaning chain following its pre-order,
+    /// so that every left child is a `Leaf` and every right child is the
+    /// next node in pre-order. The result is effectively a linked list, and
+    /// is generally no longer a search tree.
+    pub fn flatten_preorder(self) -> BinaryTree<T> {
+        self.flatten_preorder_onto(BinaryTree::Leaf)
+    }
+
+    // Flatten this tree in pre-order onto the front of the already flattened
+    // chain `tail`, moving the values out of the nodes as it goes.
+    fn flatten_preorder_onto(self, tail: BinaryTree<T>) -> BinaryTree<T> {
+        match self {
+            BinaryTree::Node(i, l, r) => {
+                let rest = l.flatten_preorder_onto(r.flatten_preorder_onto(tail));
+                BinaryTree::Node(i, Box::new(BinaryTree::Leaf), Box::new(rest))
+            },
+            BinaryTree::Leaf => tail,
+        }
+    }
+
+    /// Count the downward paths, starting and ending at any node, whose
+    /// values sum to `target`. Each node extends the sum of every path
+    /// ending at its parent, which takes O(n·h) time and a `Vec` per node
+    /// for a tree of height h. A running prefix-sum map would be O(n), but
+    /// it has to subtract `target` from each prefix, which underflows for
+    /// unsigned numbers, so this version only needs every path sum to fit
+    /// in `T`.
+    pub fn count_paths_with_sum(&self, target: T) -> usize
+        where T: Add<Output=T> + Copy
+    {
+        self.count_paths_from(&[], target)
+    }
+
+    fn count_paths_from(&self, sums: &[T], target: T) -> usize
+        where T: Add<Output=T> + Copy
+    {
+        match *self {
+            BinaryTree::Node(i, ref l, ref r) => {
+                let sums: Vec<T> = sums.iter().map(|&s| s + i).chain(Some(i)).collect();
+                sums.iter().filter(|&&s| s == target).count()
+                    + l.count_paths_from(&sums, target)
+                    + r.count_paths_from(&sums, target)
+            },
+            BinaryTree::Leaf => 0,
+        }
+    }
+
+    /// Find the value in the tree nearest to `target`. Only the path a search
+    /// for `target` would take is visited, since any closer value must lie
+    /// along it. Returns `None` for an empty tree.
+    pub fn closest(&self, target: &T) -> Option<&T> where T: Sub<Output=T> + Copy {
+        let distance = |i: &T| if *i > *target { *i - *target } else { *target - *i };
+        let mut closest: Option<&T> = None;
+        let mut current = self;
+        while let BinaryTree::Node(ref i, ref l, ref r) = *current {
+            match closest {
+                Some(c) if distance(c) <= distance(i) => {},
+                _ => closest = Some(i),
+            }
+            if *target < *i {
+                current = l;
+            } else if *target > *i {
+                current = r;
+            } else {
+                break;
+            }
+        }
+        closest
+    }
+
+    /// References to the items of the tree in pre-order, visiting each node
+    /// before its left and then right subtrees.
+    pub fn preorder(&self) -> Vec<&T> {
+        match *self {
+            BinaryTree::Node(ref i, ref l, ref r) => {
+                let mut order = vec![i];
+                order.extend(l.preorder());
+                order.extend(r.preorder());
+                order
+            },
+            BinaryTree::Leaf => Vec::new(),
+        }
+    }
+
+    /// References to the items of the tree in post-order, visiting the left
+    /// and then right subtrees of each node before the node itself.
+    pub fn postorder(&self) -> Vec<&T> {
+        match *self {
+            BinaryTree::Node(ref i, ref l, ref r) => {
+                let mut order = l.postorder();
+                order.extend(r.postorder());
+                order.push(i);
+                order
+            },
+            BinaryTree::Leaf => Vec::new(),
+        }
+    }
+
+    /// References to the items of the tree in level order, breadth first
+    /// from the root and left to right within each level. This shows the
+    /// shape of the tree far better than the sorted in-order traversal.
+    pub fn level_order(&self) -> Vec<&T> {
+        let mut order = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back(self);
+        while let Some(tree) = queue.pop_front() {
+            if let BinaryTree::Node(ref i, ref l, ref r) = *tree {
+                order.push(i);
+                queue.push_back(l);
+                queue.push_back(r);
+            }
+        }
+        order
+    }
+
+    /// The values visible when looking at the tree from the right, one for
+    /// each depth. A level-order traversal visits each level left to right,
+    /// so the last node seen at a depth is the one on the right side.
+    pub fn right_side_view(&self) -> Vec<T> {
+        let mut view = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((self, 0));
+        while let Some((tree, depth)) = queue.pop_front() {
+            if let BinaryTree::Node(ref i, ref l, ref r) = *tree {
+                if depth == view.len() {
+                    view.push(i.clone());
+                } else {
+                    view[depth] = i.clone();
+                }
+                queue.push_back((l, depth + 1));
+                queue.push_back((r, depth + 1));
+            }
+        }
+        view
+    }
+
+    /// The values of the tree grouped by depth, reading the first level left
+    /// to right, the next right to left, and so on alternating.
+    pub fn zigzag_level_order(&self) -> Vec<Vec<T>> {
+        let mut levels: Vec<Vec<T>> = Vec::new();
+        let mut queue = VecDeque::new();
+        queue.push_back((self, 0));
+        while let Some((tree, depth)) = queue.pop_front() {
+            if let BinaryTree::Node(ref i, ref l, ref r) = *tree {
+                if depth == levels.len() {
+                    levels.push(Vec::new());
+                }
+                levels[depth].push(i.clone());
+                queue.push_back((l, depth + 1));
+                queue.push_back((r, depth + 1));
+            }
+        }
+        for level in levels.iter_mut().skip(1).step_by(2) {
+            level.reverse();
+        }
+        levels
+    }
+
+    /// The balance factor of the root, its left subtree's depth minus its
+    /// right subtree's depth, where an empty subtree has depth 0. Positive
+    /// values mean the tree leans left, negative values that it leans right.
+    pub fn balance_factor(&self) -> i64 {
+        match *self {
+            BinaryTree::Node(_, ref l, ref r) => l.depth() as i64 - r.depth() as i64,
+            BinaryTree::Leaf => 0,
+        }
+    }
+
+    /// The balance factor of every node in the tree, in pre-order.
+    pub fn node_balance_factors(&self) -> Vec<(T, i64)> {
+        let mut factors = Vec::new();
+        self.collect_balance_factors(&mut factors);
+        factors
+    }
+
+    // Returns the same value as `depth`, collecting the balance factors of
+    // every node along the way so the tree is only walked once.
+    fn collect_balance_factors(&self, factors: &mut Vec<(T, i64)>) -> i64 {
+        match *self {
+            BinaryTree::Node(ref i, ref l, ref r) => {
+                let index = factors.len();
+                factors.push((i.clone(), 0));
+                let left = l.collect_balance_factors(factors);
+                let right = r.collect_balance_factors(factors);
+                factors[index].1 = left - right;
+                1 + left.max(right)
+            },
+            BinaryTree::Leaf => 0,
+        }
+    }
+
+    /// Read every root to leaf path as the decimal digits of a number, and
+    /// return the sum of those numbers. For example a root of `1` with the
+    /// children `2` and `3` gives `12 + 13 = 25`. Values are expected to be
+    /// single digits, and an empty tree sums to 0.
+    pub fn root_to_leaf_numbers(&self) -> T where T: Add<Output=T> + Mul<Output=T> + From<u8> + Copy {
+        self.sum_leaf_numbers(T::from(0))
+    }
+
+    fn sum_leaf_numbers(&self, prefix: T) -> T where T: Add<Output=T> + Mul<Output=T> + From<u8> + Copy {
+        match *self {
+            BinaryTree::Node(i, ref l, ref r) => {
+                let number = prefix * T::from(10) + i;
+                match (&**l, &**r) {
+                    (BinaryTree::Leaf, BinaryTree::Leaf) => number,
+                    _ => l.sum_leaf_numbers(number) + r.sum_leaf_numbers(number),
+                }
+            },
+            BinaryTree::Leaf => T::from(0),
+        }
+    }
+
+    /// The number of nodes in the largest subtree which is itself a valid
+    /// search tree, with smaller or equal values to the left and larger
+    /// values to the right. This is useful for trees built directly from the
+    /// enum, which may not respect the ordering `push` maintains.
+    pub fn largest_bst_subtree_size(&self) -> usize {
+        self.bst_summary().3
+    }
+
+    // Bottom up summary of a subtree: whether it's a valid search tree, its
+    // size and bounds when it is, and the size of the largest valid search
+    // tree found within it.
+    fn bst_summary(&self) -> (bool, usize, Option<(&T, &T)>, usize) {
+        match *self {
+            BinaryTree::Node(ref i, ref l, ref r) => {
+                let (l_bst, l_size, l_bounds, l_best) = l.bst_summary();
+                let (r_bst, r_size, r_bounds, r_best) = r.bst_summary();
+                let fits_left = l_bounds.is_none_or(|(_, max)| max <= i);
+                let fits_right = r_bounds.is_none_or(|(min, _)| min > i);
+                if l_bst && r_bst && fits_left && fits_right {
+                    let min = l_bounds.map_or(i, |(min, _)| min);
+                    let max = r_bounds.map_or(i, |(_, max)| max);
+                    let size = l_size + r_size + 1;
+                    (true, size, Some((min, max)), size)
+                } else {
+                    (false, 0, None, l_best.max(r_best))
+                }
+            },
+            BinaryTree::Leaf => (true, 0, None, 0),
+        }
+    }
+
+    /// The items of the tree in sorted order, collected into a `Vec`.
+    pub fn flatten(&self) -> Vec<T> {
+        match *self {
+            BinaryTree::Node(ref i, ref l, ref r) => {
+                let mut flat = l.flatten();
+                flat.push(i.clone());
+                flat.extend(r.flatten());
+                flat
+            },
+            BinaryTree::Leaf => Vec::new(),
+        }
     }
 }
 
-impl<T: PartialEq + PartialOrd + Copy> Add for BinaryTree<T> {
+/// Adding two trees pushes every item of the right hand tree into the left
+/// hand tree, giving a search tree holding the items of both. The shape of
+/// the result is not balanced.
+impl<T: PartialEq + PartialOrd + Clone> Add for BinaryTree<T> {
     type Output = BinaryTree<T>;
 
-    // TODO
-    fn add(self, _rhs: BinaryTree<T>) -> BinaryTree<T> {
-        self
+    fn add(self, rhs: BinaryTree<T>) -> BinaryTree<T> {
+        rhs.into_iter().fold(self, BinaryTree::push)
+    }
+}
+
+/// Building a tree from an iterator pushes each item in turn, so the result
+/// is a search tree shaped by the order of the items. An empty iterator gives
+/// an empty tree, the same as `new()`.
+impl<T: PartialEq + PartialOrd + Clone> FromIterator<T> for BinaryTree<T> {
+    fn from_iter<I: IntoIterator<Item=T>>(iterable: I) -> BinaryTree<T> {
+        iterable.into_iter().fold(BinaryTree::new(), BinaryTree::push)
+    }
+}
+
+/// Iterating over a reference to a tree visits the items in order, from
+/// smallest to largest. The iterator keeps an explicit stack of the nodes
+/// whose left subtrees are still being visited.
+impl<'a, T: PartialEq + PartialOrd + Clone> IntoIterator for &'a BinaryTree<T> {
+    type Item = &'a T;
+    type IntoIter = Iter<'a, T>;
+
+    fn into_iter(self) -> Iter<'a, T> {
+        let mut iter = Iter { stack: Vec::new() };
+        iter.push_left(self);
+        iter
+    }
+}
+
+/// Iterating over a tree by value visits the items in order, from smallest
+/// to largest, moving them out of the tree.
+impl<T: PartialEq + PartialOrd + Clone> IntoIterator for BinaryTree<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> IntoIter<T> {
+        let mut iter = IntoIter { stack: Vec::new() };
+        iter.push_left(self);
+        iter
+    }
+}
+
+impl<'a, T: PartialEq + PartialOrd + Clone> Iter<'a, T> {
+    // Stack every node down the left spine of `tree`, so the smallest item
+    // ends up on top.
+    fn push_left(&mut self, mut tree: &'a BinaryTree<T>) {
+        while let BinaryTree::Node(ref i, ref l, ref r) = *tree {
+            self.stack.push((i, r));
+            tree = l;
+        }
+    }
+}
+
+impl<T: PartialEq + PartialOrd + Clone> IntoIter<T> {
+    fn push_left(&mut self, mut tree: BinaryTree<T>) {
+        while let BinaryTree::Node(i, l, r) = tree {
+            self.stack.push((i, *r));
+            tree = *l;
+        }
+    }
+}
+
+impl<'a, T: PartialEq + PartialOrd + Clone> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        let (item, right) = self.stack.pop()?;
+        self.push_left(right);
+        Some(item)
     }
 }
 
-impl<T: PartialEq + PartialOrd + Copy> Iterator for BinaryTree<T> {
+impl<T: PartialEq + PartialOrd + Clone> Iterator for IntoIter<T> {
     type Item = T;
 
-    // TODO
     fn next(&mut self) -> Option<T> {
-        None
+        let (item, right) = self.stack.pop()?;
+        self.push_left(right);
+        Some(item)
     }
 }
 
 #[cfg(test)]
 mod test {
+    use std::iter::FromIterator;
     use super::BinaryTree;
 
     #[test]
@@ -110,6 +603,27 @@ mod test {
         assert_eq!(new_tree, tree);
     }
 
+    #[test]
+    fn test_is_empty() {
+        assert!(BinaryTree::<i32>::new().is_empty());
+        assert!(!BinaryTree::new().push(1).is_empty());
+    }
+
+    #[test]
+    fn test_len() {
+        assert_eq!(BinaryTree::<i32>::new().len(), 0);
+        assert_eq!(BinaryTree::new().push(1).len(), 1);
+        assert_eq!(BinaryTree::new().push(5).push(3).push(8).push(1).len(), 4);
+    }
+
+    #[test]
+    fn test_height() {
+        assert_eq!(BinaryTree::<i32>::new().height(), 0);
+        assert_eq!(BinaryTree::new().push(1).height(), 0);
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(2);
+        assert_eq!(tree.height(), 3);
+    }
+
     #[test]
     fn test_push() {
         let push_tree = BinaryTree::new().push(42);
@@ -117,13 +631,71 @@ mod test {
         assert_eq!(push_tree, tree);
     }
 
-    // #[test]
-    // fn test_pop_first() {
-    //     let (item, pop_first_tree) = BinaryTree::new().push(5).push(1).push(7).pop_first().unwrap();
-    //     let tree = BinaryTree::new().push(5).push(7);
-    //     assert_eq!(item, 1);
-    //     assert_eq!(pop_first_tree, tree);
-    // }
+    #[test]
+    fn test_contains() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4);
+        assert!(tree.contains(&4));
+        assert!(tree.contains(&5));
+        assert!(!tree.contains(&0));
+        assert!(!tree.contains(&9));
+        assert!(!BinaryTree::new().contains(&1));
+    }
+
+    #[test]
+    fn test_minimum() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(4).push(9);
+        assert_eq!(tree.minimum(), Some(&3));
+        assert_eq!(BinaryTree::<i32>::new().minimum(), None);
+    }
+
+    #[test]
+    fn test_maximum() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(4).push(7);
+        assert_eq!(tree.maximum(), Some(&8));
+        assert_eq!(BinaryTree::<i32>::new().maximum(), None);
+    }
+
+    #[test]
+    fn test_pop_first() {
+        let (item, pop_first_tree) = BinaryTree::new().push(5).push(1).push(7).pop_first().unwrap();
+        let tree = BinaryTree::new().push(5).push(7);
+        assert_eq!(item, 1);
+        assert_eq!(pop_first_tree, tree);
+    }
+
+    #[test]
+    fn test_pop_first_promotes_right() {
+        let (item, pop_first_tree) = BinaryTree::new().push(5).push(1).push(3).pop_first().unwrap();
+        let tree = BinaryTree::new().push(5).push(3);
+        assert_eq!(item, 1);
+        assert_eq!(pop_first_tree, tree);
+    }
+
+    #[test]
+    fn test_pop_first_empty() {
+        assert_eq!(BinaryTree::<i32>::new().pop_first(), None);
+    }
+
+    #[test]
+    fn test_pop_last() {
+        let (item, pop_last_tree) = BinaryTree::new().push(5).push(1).push(7).pop_last().unwrap();
+        let tree = BinaryTree::new().push(5).push(1);
+        assert_eq!(item, 7);
+        assert_eq!(pop_last_tree, tree);
+    }
+
+    #[test]
+    fn test_pop_last_promotes_left() {
+        let (item, pop_last_tree) = BinaryTree::new().push(5).push(9).push(7).pop_last().unwrap();
+        let tree = BinaryTree::new().push(5).push(7);
+        assert_eq!(item, 9);
+        assert_eq!(pop_last_tree, tree);
+    }
+
+    #[test]
+    fn test_pop_last_empty() {
+        assert_eq!(BinaryTree::<i32>::new().pop_last(), None);
+    }
 
     #[test]
     fn test_remove() {
@@ -133,28 +705,347 @@ mod test {
         assert_eq!(remove_tree, tree);
     }
 
+    #[test]
+    fn test_remove_leaf() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(7).push(9);
+        let (item, remove_tree) = tree.remove(1).unwrap();
+        assert_eq!(item, 1);
+        assert_eq!(remove_tree, BinaryTree::new().push(5).push(3).push(8).push(4).push(7).push(9));
+    }
+
+    #[test]
+    fn test_remove_one_child() {
+        let tree = BinaryTree::new().push(5).push(3).push(1).push(8);
+        let (item, remove_tree) = tree.remove(3).unwrap();
+        assert_eq!(item, 3);
+        assert_eq!(remove_tree, BinaryTree::new().push(5).push(1).push(8));
+    }
+
+    #[test]
+    fn test_remove_two_children() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(7).push(9);
+        let (item, remove_tree) = tree.remove(5).unwrap();
+        assert_eq!(item, 5);
+        assert_eq!(remove_tree, BinaryTree::new().push(4).push(3).push(8).push(1).push(7).push(9));
+
+        let tree = BinaryTree::new().push(5).push(3).push(7).push(7);
+        let (item, remove_tree) = tree.remove(5).unwrap();
+        assert_eq!(item, 5);
+        assert_eq!(remove_tree, BinaryTree::new().push(3).push(7).push(7));
+        assert_eq!(remove_tree.flatten(), vec![3, 7, 7]);
+        assert_eq!(remove_tree.largest_bst_subtree_size(), remove_tree.len());
+
+        let tree = BinaryTree::new().push(5).push(3).push(3).push(7);
+        let (_, remove_tree) = tree.remove(5).unwrap();
+        assert_eq!(remove_tree.flatten(), vec![3, 3, 7]);
+        assert_eq!(remove_tree.largest_bst_subtree_size(), remove_tree.len());
+    }
+
+    #[test]
+    fn test_remove_missing() {
+        let tree = BinaryTree::new().push(5).push(3).push(8);
+        assert_eq!(tree.remove(4), None);
+    }
+
+    #[test]
+    fn test_is_subtree_of() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4);
+        let subtree = BinaryTree::new().push(3).push(1).push(4);
+        assert!(subtree.is_subtree_of(&tree));
+        assert!(tree.is_subtree_of(&tree));
+    }
+
+    #[test]
+    fn test_is_subtree_of_wrong_structure() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4);
+        let subtree = BinaryTree::new().push(3).push(1);
+        assert!(!subtree.is_subtree_of(&tree));
+    }
+
+    #[test]
+    fn test_is_subtree_of_missing() {
+        let tree = BinaryTree::new().push(5).push(3).push(8);
+        let subtree = BinaryTree::new().push(7);
+        assert!(!subtree.is_subtree_of(&tree));
+        assert!(!tree.is_subtree_of(&BinaryTree::Leaf));
+    }
+
+    #[test]
+    fn test_is_subtree_of_empty() {
+        let empty: BinaryTree<i32> = BinaryTree::new();
+        assert!(empty.is_subtree_of(&BinaryTree::new().push(1)));
+        assert!(empty.is_subtree_of(&BinaryTree::Leaf));
+    }
+
+    #[test]
+    fn test_from_traversals() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4);
+        let rebuilt = BinaryTree::from_traversals(&[5, 3, 1, 4, 8], &[1, 3, 4, 5, 8]);
+        assert_eq!(rebuilt, Some(tree));
+    }
+
+    #[test]
+    fn test_from_traversals_arbitrary_shape() {
+        let leaf = || Box::new(BinaryTree::Leaf);
+        let tree = BinaryTree::Node(1, Box::new(BinaryTree::Node(2, Box::new(BinaryTree::Node(3, leaf(), leaf())), leaf())), leaf());
+        assert_eq!(BinaryTree::from_traversals(&[1, 2, 3], &[3, 2, 1]), Some(tree));
+        assert_eq!(BinaryTree::<i32>::from_traversals(&[], &[]), Some(BinaryTree::Leaf));
+    }
+
+    #[test]
+    fn test_from_traversals_inconsistent() {
+        assert_eq!(BinaryTree::from_traversals(&[1, 2], &[1]), None);
+        assert_eq!(BinaryTree::from_traversals(&[1, 2, 3], &[1, 2, 4]), None);
+        assert_eq!(BinaryTree::from_traversals(&[1, 2], &[1, 1]), None);
+    }
+
+    #[test]
+    fn test_serialize() {
+        let tree = BinaryTree::new().push(2).push(1).push(3);
+        assert_eq!(tree.serialize(), vec![Some(2), Some(1), None, None, Some(3), None, None]);
+        assert_eq!(BinaryTree::<i32>::new().serialize(), vec![None]);
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let trees = vec![
+            BinaryTree::new(),
+            BinaryTree::new().push(42),
+            BinaryTree::new().push(5).push(3).push(8).push(1).push(4),
+            BinaryTree::new().push(1).push(2).push(3).push(4),
+        ];
+        for tree in trees {
+            assert_eq!(BinaryTree::deserialize(&tree.serialize()), tree);
+        }
+    }
+
+    #[test]
+    fn test_count_paths_with_sum() {
+        let tree = BinaryTree::deserialize(&[
+            Some(10),
+            Some(5), Some(3), Some(3), None, None, Some(-2), None, None,
+                     Some(2), None, Some(1), None, None,
+            Some(-3), None, Some(11), None, None,
+        ]);
+        assert_eq!(tree.count_paths_with_sum(8), 3);
+        assert_eq!(tree.count_paths_with_sum(100), 0);
+    }
+
+    #[test]
+    fn test_count_paths_with_sum_single_node() {
+        let tree = BinaryTree::new().push(2).push(1).push(3);
+        assert_eq!(tree.count_paths_with_sum(3), 2);
+        assert_eq!(tree.count_paths_with_sum(1), 1);
+        assert_eq!(BinaryTree::new().count_paths_with_sum(0), 0);
+    }
+
+    #[test]
+    fn test_count_paths_with_sum_unsigned() {
+        let tree = BinaryTree::<u32>::new().push(2).push(1).push(3);
+        assert_eq!(tree.count_paths_with_sum(3), 2);
+        assert_eq!(tree.count_paths_with_sum(5), 1);
+        assert_eq!(tree.count_paths_with_sum(4), 0);
+    }
+
+    #[test]
+    fn test_count_paths_with_sum_near_overflow() {
+        let tree = BinaryTree::<u8>::new().push(60).push(70);
+        assert_eq!(tree.count_paths_with_sum(200), 0);
+        assert_eq!(tree.count_paths_with_sum(130), 1);
+        let tree = BinaryTree::<u32>::new().push(1);
+        assert_eq!(tree.count_paths_with_sum(u32::MAX), 0);
+    }
+
+    #[test]
+    fn test_closest() {
+        let tree = BinaryTree::new().push(10).push(4).push(20).push(1).push(7).push(15);
+        assert_eq!(tree.closest(&14), Some(&15));
+        assert_eq!(tree.closest(&8), Some(&7));
+        assert_eq!(tree.closest(&6), Some(&7));
+        assert_eq!(tree.closest(&7), Some(&7));
+        assert_eq!(tree.closest(&100), Some(&20));
+    }
+
+    #[test]
+    fn test_closest_empty() {
+        let tree: BinaryTree<u32> = BinaryTree::new();
+        assert_eq!(tree.closest(&5), None);
+    }
+
+    #[test]
+    fn test_preorder() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(9);
+        assert_eq!(tree.preorder(), vec![&5, &3, &1, &4, &8, &9]);
+        assert!(BinaryTree::<i32>::new().preorder().is_empty());
+    }
+
+    #[test]
+    fn test_postorder() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(9);
+        assert_eq!(tree.postorder(), vec![&1, &4, &3, &9, &8, &5]);
+        assert!(BinaryTree::<i32>::new().postorder().is_empty());
+    }
+
+    #[test]
+    fn test_level_order() {
+        let tree = BinaryTree::new().push(5).push(3).push(8);
+        assert_eq!(tree.level_order(), vec![&5, &3, &8]);
+        let tree = tree.push(1).push(4).push(9);
+        assert_eq!(tree.level_order(), vec![&5, &3, &8, &1, &4, &9]);
+    }
+
+    #[test]
+    fn test_level_order_empty() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert!(tree.level_order().is_empty());
+    }
+
+    #[test]
+    fn test_right_side_view() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(9);
+        assert_eq!(tree.right_side_view(), vec![5, 8, 9]);
+        assert_eq!(BinaryTree::<i32>::new().right_side_view(), vec![]);
+    }
+
+    #[test]
+    fn test_right_side_view_left_heavy() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(2);
+        assert_eq!(tree.right_side_view(), vec![5, 8, 1, 2]);
+    }
+
+    #[test]
+    fn test_zigzag_level_order() {
+        let tree = BinaryTree::new().push(8).push(4).push(12).push(2).push(6).push(10).push(14).push(1).push(15);
+        assert_eq!(tree.zigzag_level_order(), vec![vec![8], vec![12, 4], vec![2, 6, 10, 14], vec![15, 1]]);
+        assert_eq!(BinaryTree::<i32>::new().zigzag_level_order(), Vec::<Vec<i32>>::new());
+    }
+
+    #[test]
+    fn test_balance_factor_left_heavy() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(2).push(1);
+        assert_eq!(tree.balance_factor(), 2);
+        assert_eq!(tree.node_balance_factors(), vec![(5, 2), (3, 2), (2, 1), (1, 0), (8, 0)]);
+    }
+
+    #[test]
+    fn test_balance_factor_balanced() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4);
+        assert_eq!(tree.balance_factor(), 1);
+        let tree = tree.push(9).push(7);
+        assert_eq!(tree.balance_factor(), 0);
+        assert!(tree.node_balance_factors().iter().all(|&(_, f)| f == 0));
+        assert_eq!(BinaryTree::<i32>::new().balance_factor(), 0);
+    }
+
+    #[test]
+    fn test_flatten_preorder() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(9);
+        let mut current = tree.flatten_preorder();
+        let mut walked = vec![];
+        while let BinaryTree::Node(i, l, r) = current {
+            assert_eq!(*l, BinaryTree::Leaf);
+            walked.push(i);
+            current = *r;
+        }
+        assert_eq!(walked, vec![5, 3, 1, 4, 8, 9]);
+    }
+
+    #[test]
+    fn test_root_to_leaf_numbers() {
+        let tree = BinaryTree::new().push(4).push(2).push(9).push(1);
+        assert_eq!(tree.root_to_leaf_numbers(), 421 + 49);
+        assert_eq!(BinaryTree::<u32>::new().root_to_leaf_numbers(), 0);
+    }
+
+    #[test]
+    fn test_root_to_leaf_numbers_single_node() {
+        let tree = BinaryTree::new().push(7u64);
+        assert_eq!(tree.root_to_leaf_numbers(), 7);
+    }
+
+    #[test]
+    fn test_largest_bst_subtree_size() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(9);
+        assert_eq!(tree.largest_bst_subtree_size(), 6);
+        assert_eq!(BinaryTree::<i32>::new().largest_bst_subtree_size(), 0);
+    }
+
+    #[test]
+    fn test_largest_bst_subtree_size_violating() {
+        let tree = BinaryTree::deserialize(&[
+            Some(10),
+            Some(5), Some(1), None, None, Some(8), None, None,
+            Some(15), None, Some(7), None, None,
+        ]);
+        assert_eq!(tree.largest_bst_subtree_size(), 3);
+    }
+
+    #[test]
+    fn test_flatten() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(9);
+        assert_eq!(tree.flatten(), vec![1, 3, 4, 5, 8, 9]);
+    }
+
+    #[test]
+    fn test_flatten_empty() {
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!(tree.flatten(), vec![]);
+    }
+
+    #[test]
+    fn test_strings() {
+        let tree = BinaryTree::new().push("pear".to_string())
+                                    .push("apple".to_string())
+                                    .push("quince".to_string())
+                                    .push("fig".to_string());
+        assert!(tree.contains(&"fig".to_string()));
+        assert_eq!(tree.flatten(), vec!["apple", "fig", "pear", "quince"]);
+    }
+
+    #[test]
+    fn test_from_iter() {
+        let tree = BinaryTree::from_iter(vec![5, 3, 8, 1]);
+        assert_eq!(tree, BinaryTree::new().push(5).push(3).push(8).push(1));
+        assert_eq!(tree.flatten(), vec![1, 3, 5, 8]);
+    }
+
+    #[test]
+    fn test_from_iter_empty() {
+        let tree = BinaryTree::from_iter(Vec::<i32>::new());
+        assert_eq!(tree, BinaryTree::new());
+    }
+
     #[test]
     fn test_default() {
         let default_tree: BinaryTree<&str> = BinaryTree::default();
         assert_eq!(default_tree, BinaryTree::Leaf);
     }
 
-    // #[test]
-    // fn test_add() {
-    //     let add_tree = BinaryTree::new().push(2).push(5).push(0) +
-    //                    BinaryTree::new().push(3).push(1).push(7);
-    //     assert_eq!(add_tree.count(), 6);
-    // }
-    
-    // #[test]
-    // fn test_count() {
-    //     let count_tree = BinaryTree::new().push(12).push(2);
-    //     assert_eq!(count_tree.count(), 2);
-    // }
-    
-    // #[test]
-    // fn test_collect() {
-    //     let collect_tree = BinaryTree::new().push(2).push(5).push(0);
-    //     assert_eq!(collect_tree.collect::<Vec<i32>>(), [0, 2, 5]);
-    // }
+    #[test]
+    fn test_add() {
+        let add_tree = BinaryTree::new().push(2).push(5).push(0) +
+                       BinaryTree::new().push(3).push(1).push(7);
+        assert_eq!(add_tree.clone().into_iter().count(), 6);
+        assert_eq!(add_tree.flatten(), vec![0, 1, 2, 3, 5, 7]);
+    }
+
+    #[test]
+    fn test_count() {
+        let count_tree = BinaryTree::new().push(12).push(2);
+        assert_eq!(count_tree.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn test_collect() {
+        let collect_tree = BinaryTree::new().push(2).push(5).push(0);
+        assert_eq!(collect_tree.into_iter().collect::<Vec<i32>>(), [0, 2, 5]);
+    }
+
+    #[test]
+    fn test_iter() {
+        let tree = BinaryTree::new().push(5).push(3).push(8).push(1).push(4).push(7).push(9);
+        assert_eq!((&tree).into_iter().collect::<Vec<_>>(), vec![&1, &3, &4, &5, &7, &8, &9]);
+        let tree: BinaryTree<i32> = BinaryTree::new();
+        assert_eq!((&tree).into_iter().next(), None);
+    }
 }